@@ -63,12 +63,29 @@
 /// The macro is meant to provide easy means to enhance the semantics of language built-ins.
 ///
 /// Newtypes come with `Deref`, `DerefMut`, `AsRef`, `AsMut`, and `From` traits.
-/// Further they implement almost all std::ops and std::cmp of the type they wrap if the operants have value semantics and return `Self`.
+/// Further they implement almost all std::ops and std::cmp of the type they wrap.
 /// Exceptions are std::ops::{`Drop`, `Fn`, `FnMut`, `FnOnce`, `Index`, `IndexMut`, `RangeBounds`}.
 ///
+/// A binary op forwards to the inner type's own associated `Output`, so the result is not
+/// necessarily `Self` — e.g. an inner `Ft * Ft -> Sqft` yields `Area<Ft> * Area<Ft> -> Area<Sqft>`.
+/// Two newtype operands must share the same inner type (`$newtype<T> op $newtype<T>`); mixing
+/// inner types (`$newtype<T> op $newtype<R>`) is not supported, as it would overlap with the
+/// arithmetic against the bare inner value (`$newtype<T> op T`) on nested newtypes. Shifts are the
+/// one exception: `Shl`/`Shr` take the raw count directly (`$newtype<T> << R`), so `newtype << newtype`
+/// is intentionally not implemented — shift by the inner count instead (`bits << *count`).
+///
 /// Usually one obtains instances of the newtype by the public constructor but `Default` is available if the wrapped type implements it.
 /// It is not as ergonomic as it should be though, see examples below.
 ///
+/// By default every trait group is implemented. Since arithmetic on identifier-like newtypes is
+/// usually nonsensical, the set can be narrowed with a trailing `; only:` or `; except:` clause
+/// listing trait groups (`Eq`, `Ord`, `Hash`, `Add`, `Sub`, `Mul`, `Div`, `Rem`, `Neg`, `Not`,
+/// `BitAnd`, `BitOr`, `BitXor`, `Shl`, `Shr`, `Display`, `Binary`, `Octal`, `LowerHex`,
+/// `UpperHex`, `LowerExp`, `UpperExp`, `Pointer`, `FromStr`, `IntoIterator`, `Extend`, `Sum`,
+/// `Product`). The wrapper traits above are always implemented. `Iterator` is available too but,
+/// because it would collide with the default `IntoIterator` forwarding, it is opt-in only via
+/// `; only: Iterator`.
+///
 /// # Examples
 ///
 /// Operations are available on newtypes:
@@ -127,255 +144,925 @@
 /// assert_eq!(abc_one + abc_two, A(B(C(10))))
 /// # }
 /// ```
+/// Arithmetic can be opted out of where it makes no sense:
+/// ```rust
+/// # #[macro_use] extern crate new_type;
+/// # fn main() {
+/// // An identifier only needs equality, ordering and hashing.
+/// newtype!(Id: u64; only: Eq, Ord, Hash);
+/// assert!(Id(1) < Id(2));
+///
+/// // Compilation error: Id does not implement `Add`.
+/// // let _ = Id(1) + Id(2);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! newtype {
-    ( $( $newtype:ident $( : $default:ty )? ),* ) => {
+    ( $newtype:ident $( : $default:ty )? ; only : $( $tag:ident ),+ $(,)? ) => {
+        $crate::newtype!(@struct $newtype $( : $default )? );
+        $( $crate::newtype!(@group $tag $newtype); )+
+    };
+
+    ( $newtype:ident $( : $default:ty )? ; except : $( $ex:ident ),+ $(,)? ) => {
+        $crate::newtype!(@struct $newtype $( : $default )? );
+        macro_rules! __newtype_except {
+            $( ( $ex $nn:ident ) => {}; )+
+            ( $tt:ident $nn:ident ) => { $crate::newtype!(@group $tt $nn); };
+        }
+        __newtype_except!(Eq $newtype);
+        __newtype_except!(Ord $newtype);
+        __newtype_except!(Hash $newtype);
+        __newtype_except!(Add $newtype);
+        __newtype_except!(Sub $newtype);
+        __newtype_except!(Mul $newtype);
+        __newtype_except!(Div $newtype);
+        __newtype_except!(Rem $newtype);
+        __newtype_except!(Neg $newtype);
+        __newtype_except!(Not $newtype);
+        __newtype_except!(BitAnd $newtype);
+        __newtype_except!(BitOr $newtype);
+        __newtype_except!(BitXor $newtype);
+        __newtype_except!(Shl $newtype);
+        __newtype_except!(Shr $newtype);
+        __newtype_except!(Display $newtype);
+        __newtype_except!(Binary $newtype);
+        __newtype_except!(Octal $newtype);
+        __newtype_except!(LowerHex $newtype);
+        __newtype_except!(UpperHex $newtype);
+        __newtype_except!(LowerExp $newtype);
+        __newtype_except!(UpperExp $newtype);
+        __newtype_except!(Pointer $newtype);
+        __newtype_except!(FromStr $newtype);
+        __newtype_except!(IntoIterator $newtype);
+        __newtype_except!(Extend $newtype);
+        __newtype_except!(Sum $newtype);
+        __newtype_except!(Product $newtype);
+    };
+
+    ( $( $newtype:ident $( : $default:ty )? ),+ $(,)? ) => {
         $(
-            #[derive(Debug)]
-            pub struct $newtype<T $( =$default )? >(pub T);
+            $crate::newtype!(@struct $newtype $( : $default )? );
+            $crate::newtype!(@group Eq $newtype);
+            $crate::newtype!(@group Ord $newtype);
+            $crate::newtype!(@group Hash $newtype);
+            $crate::newtype!(@group Add $newtype);
+            $crate::newtype!(@group Sub $newtype);
+            $crate::newtype!(@group Mul $newtype);
+            $crate::newtype!(@group Div $newtype);
+            $crate::newtype!(@group Rem $newtype);
+            $crate::newtype!(@group Neg $newtype);
+            $crate::newtype!(@group Not $newtype);
+            $crate::newtype!(@group BitAnd $newtype);
+            $crate::newtype!(@group BitOr $newtype);
+            $crate::newtype!(@group BitXor $newtype);
+            $crate::newtype!(@group Shl $newtype);
+            $crate::newtype!(@group Shr $newtype);
+            $crate::newtype!(@group Display $newtype);
+            $crate::newtype!(@group Binary $newtype);
+            $crate::newtype!(@group Octal $newtype);
+            $crate::newtype!(@group LowerHex $newtype);
+            $crate::newtype!(@group UpperHex $newtype);
+            $crate::newtype!(@group LowerExp $newtype);
+            $crate::newtype!(@group UpperExp $newtype);
+            $crate::newtype!(@group Pointer $newtype);
+            $crate::newtype!(@group FromStr $newtype);
+            $crate::newtype!(@group IntoIterator $newtype);
+            $crate::newtype!(@group Extend $newtype);
+            $crate::newtype!(@group Sum $newtype);
+            $crate::newtype!(@group Product $newtype);
+        )+
+    };
+
+    (@struct $newtype:ident $( : $default:ty )? ) => {
+        #[derive(Debug)]
+        pub struct $newtype<T $( = $default )?>(pub T);
+
+        impl<U, T: std::iter::FromIterator<U>> std::iter::FromIterator<U> for $newtype<T> {
+            fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+                Self(T::from_iter(iter))
+            }
+        }
+
+        impl<T: std::default::Default> std::default::Default for $newtype<T> {
+            fn default() -> Self {
+                Self(T::default())
+            }
+        }
+
+        impl<T> std::convert::From<T> for $newtype<T> {
+            fn from(other: T) -> Self {
+                Self(other)
+            }
+        }
+
+        impl<T> std::ops::Deref for $newtype<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T> std::ops::DerefMut for $newtype<T> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl<T> std::convert::AsRef<T> for $newtype<T> {
+            fn as_ref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> std::convert::AsMut<T> for $newtype<T> {
+            fn as_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<T: std::clone::Clone> std::clone::Clone for $newtype<T> {
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl<T: std::marker::Copy> std::marker::Copy for $newtype<T> {}
+    };
+
+    (@group Eq $newtype:ident) => {
+        impl<T: std::cmp::PartialEq> std::cmp::PartialEq for $newtype<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<T: std::cmp::Eq> std::cmp::Eq for $newtype<T> {}
+    };
+
+    (@group Ord $newtype:ident) => {
+        impl<T: std::cmp::PartialOrd> std::cmp::PartialOrd for $newtype<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        impl<T: std::cmp::Ord> std::cmp::Ord for $newtype<T> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+    };
+
+    (@group Hash $newtype:ident) => {
+        impl<T: std::hash::Hash> std::hash::Hash for $newtype<T> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+    };
+
+    (@group Add $newtype:ident) => {
+        impl<T> std::ops::Add<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Add<T>,
+        {
+            type Output = $newtype<<T as std::ops::Add<T>>::Output>;
+
+            fn add(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: std::ops::Add<Output = T>> std::ops::Add<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn add(self, rhs: T) -> Self::Output {
+                $newtype(self.0 + rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::Add<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Add<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn add(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 + &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::Add<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Add<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn add(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 + &rhs.0)
+            }
+        }
+
+        impl<'a, T> std::ops::Add<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Add<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn add(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 + rhs.0)
+            }
+        }
+
+        impl<T: std::ops::AddAssign> std::ops::AddAssign for $newtype<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: std::ops::AddAssign> std::ops::AddAssign<T> for $newtype<T> {
+            fn add_assign(&mut self, rhs: T) {
+                self.0 += rhs;
+            }
+        }
+    };
+
+    (@group Sub $newtype:ident) => {
+        impl<T> std::ops::Sub<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Sub<T>,
+        {
+            type Output = $newtype<<T as std::ops::Sub<T>>::Output>;
+
+            fn sub(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: std::ops::Sub<Output = T>> std::ops::Sub<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn sub(self, rhs: T) -> Self::Output {
+                $newtype(self.0 - rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::Sub<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Sub<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn sub(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 - &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::Sub<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Sub<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn sub(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 - &rhs.0)
+            }
+        }
+
+        impl<'a, T> std::ops::Sub<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Sub<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn sub(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 - rhs.0)
+            }
+        }
+
+        impl<T: std::ops::SubAssign> std::ops::SubAssign for $newtype<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: std::ops::SubAssign> std::ops::SubAssign<T> for $newtype<T> {
+            fn sub_assign(&mut self, rhs: T) {
+                self.0 -= rhs;
+            }
+        }
+    };
+
+    (@group Mul $newtype:ident) => {
+        impl<T> std::ops::Mul<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Mul<T>,
+        {
+            type Output = $newtype<<T as std::ops::Mul<T>>::Output>;
+
+            fn mul(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 * rhs.0)
+            }
+        }
+
+        impl<T: std::ops::Mul<Output = T>> std::ops::Mul<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $newtype(self.0 * rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::Mul<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Mul<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn mul(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 * &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::Mul<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Mul<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn mul(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 * &rhs.0)
+            }
+        }
+
+        impl<'a, T> std::ops::Mul<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Mul<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn mul(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 * rhs.0)
+            }
+        }
+
+        impl<T: std::ops::MulAssign> std::ops::MulAssign for $newtype<T> {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.0 *= rhs.0;
+            }
+        }
+
+        impl<T: std::ops::MulAssign> std::ops::MulAssign<T> for $newtype<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                self.0 *= rhs;
+            }
+        }
+    };
+
+    (@group Div $newtype:ident) => {
+        impl<T> std::ops::Div<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Div<T>,
+        {
+            type Output = $newtype<<T as std::ops::Div<T>>::Output>;
 
-            impl<U, T: std::iter::FromIterator<U>> std::iter::FromIterator<U> for $newtype<T> {
-                fn from_iter<I: IntoIterator<Item=U>>(iter: I) -> Self {
-                    Self(T::from_iter(iter))
-                }
+            fn div(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 / rhs.0)
             }
+        }
 
-            impl<T: std::default::Default> std::default::Default for $newtype<T> {
-                fn default() -> Self {
-                    Self(T::default())
-                }
+        impl<T: std::ops::Div<Output = T>> std::ops::Div<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $newtype(self.0 / rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::Div<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Div<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn div(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 / &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::Div<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Div<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn div(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 / &rhs.0)
             }
+        }
 
-            impl<T> std::convert::From<T> for $newtype<T> {
-                fn from(other: T) -> Self {
-                    Self(other)
-                }
+        impl<'a, T> std::ops::Div<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Div<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn div(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 / rhs.0)
             }
+        }
 
-            impl<T> std::ops::Deref for $newtype<T> {
-                type Target = T;
+        impl<T: std::ops::DivAssign> std::ops::DivAssign for $newtype<T> {
+            fn div_assign(&mut self, rhs: Self) {
+                self.0 /= rhs.0;
+            }
+        }
 
-                fn deref(&self) -> &Self::Target {
-                    &self.0
-                }
+        impl<T: std::ops::DivAssign> std::ops::DivAssign<T> for $newtype<T> {
+            fn div_assign(&mut self, rhs: T) {
+                self.0 /= rhs;
             }
+        }
+    };
+
+    (@group Rem $newtype:ident) => {
+        impl<T> std::ops::Rem<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Rem<T>,
+        {
+            type Output = $newtype<<T as std::ops::Rem<T>>::Output>;
 
-            impl<T> std::ops::DerefMut for $newtype<T> {
-                fn deref_mut(&mut self) -> &mut Self::Target {
-                    &mut self.0
-                }
+            fn rem(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 % rhs.0)
             }
+        }
 
-            impl<T> std::convert::AsRef<T> for $newtype<T> {
-                fn as_ref(&self) -> &T {
-                    &self.0
-                }
+        impl<T: std::ops::Rem<Output = T>> std::ops::Rem<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn rem(self, rhs: T) -> Self::Output {
+                $newtype(self.0 % rhs)
             }
+        }
+
+        impl<'a, 'b, T> std::ops::Rem<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Rem<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
 
-            impl<T> std::convert::AsMut<T> for $newtype<T> {
-                fn as_mut(&mut self) -> &mut T {
-                    &mut self.0
-                }
+            fn rem(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 % &rhs.0)
             }
+        }
 
-            // std::clone and std::marker::Copy implementations
+        impl<'b, T> std::ops::Rem<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::Rem<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
 
-            impl<T: std::clone::Clone> std::clone::Clone for $newtype<T> {
-                fn clone(&self) -> Self {
-                    Self(self.0.clone())
-                }
+            fn rem(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 % &rhs.0)
             }
+        }
 
-            impl<T: std::marker::Copy> std::marker::Copy for $newtype<T> {}
+        impl<'a, T> std::ops::Rem<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::Rem<T, Output = T>,
+        {
+            type Output = $newtype<T>;
 
-            // std::cmp implementations
+            fn rem(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 % rhs.0)
+            }
+        }
 
-            impl<T: std::cmp::PartialEq> std::cmp::PartialEq for $newtype<T> {
-                fn eq(&self, other: &Self) -> bool {
-                    self.0 == other.0
-                }
+        impl<T: std::ops::RemAssign> std::ops::RemAssign for $newtype<T> {
+            fn rem_assign(&mut self, rhs: Self) {
+                self.0 %= rhs.0;
             }
+        }
+
+        impl<T: std::ops::RemAssign> std::ops::RemAssign<T> for $newtype<T> {
+            fn rem_assign(&mut self, rhs: T) {
+                self.0 %= rhs;
+            }
+        }
+    };
+
+    (@group BitAnd $newtype:ident) => {
+        impl<T> std::ops::BitAnd<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitAnd<T>,
+        {
+            type Output = $newtype<<T as std::ops::BitAnd<T>>::Output>;
+
+            fn bitand(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 & rhs.0)
+            }
+        }
+
+        impl<T: std::ops::BitAnd<Output = T>> std::ops::BitAnd<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn bitand(self, rhs: T) -> Self::Output {
+                $newtype(self.0 & rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::BitAnd<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitAnd<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitand(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 & &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::BitAnd<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitAnd<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
 
-            impl<T: std::cmp::Eq> std::cmp::Eq for $newtype<T> {}
+            fn bitand(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 & &rhs.0)
+            }
+        }
+
+        impl<'a, T> std::ops::BitAnd<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitAnd<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitand(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 & rhs.0)
+            }
+        }
+
+        impl<T: std::ops::BitAndAssign> std::ops::BitAndAssign for $newtype<T> {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl<T: std::ops::BitAndAssign> std::ops::BitAndAssign<T> for $newtype<T> {
+            fn bitand_assign(&mut self, rhs: T) {
+                self.0 &= rhs;
+            }
+        }
+    };
+
+    (@group BitOr $newtype:ident) => {
+        impl<T> std::ops::BitOr<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitOr<T>,
+        {
+            type Output = $newtype<<T as std::ops::BitOr<T>>::Output>;
+
+            fn bitor(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 | rhs.0)
+            }
+        }
+
+        impl<T: std::ops::BitOr<Output = T>> std::ops::BitOr<T> for $newtype<T> {
+            type Output = $newtype<T>;
+
+            fn bitor(self, rhs: T) -> Self::Output {
+                $newtype(self.0 | rhs)
+            }
+        }
+
+        impl<'a, 'b, T> std::ops::BitOr<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitOr<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitor(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 | &rhs.0)
+            }
+        }
+
+        impl<'b, T> std::ops::BitOr<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitOr<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitor(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 | &rhs.0)
+            }
+        }
+
+        impl<'a, T> std::ops::BitOr<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitOr<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitor(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 | rhs.0)
+            }
+        }
+
+        impl<T: std::ops::BitOrAssign> std::ops::BitOrAssign for $newtype<T> {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
 
-            impl<T: std::cmp::PartialOrd> std::cmp::PartialOrd for $newtype<T> {
-                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                    self.0.partial_cmp(&other.0)
-                }
+        impl<T: std::ops::BitOrAssign> std::ops::BitOrAssign<T> for $newtype<T> {
+            fn bitor_assign(&mut self, rhs: T) {
+                self.0 |= rhs;
             }
+        }
+    };
+
+    (@group BitXor $newtype:ident) => {
+        impl<T> std::ops::BitXor<$newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitXor<T>,
+        {
+            type Output = $newtype<<T as std::ops::BitXor<T>>::Output>;
 
-            impl<T: std::cmp::Ord> std::cmp::Ord for $newtype<T> {
-                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                    self.0.cmp(&other.0)
-                }
+            fn bitxor(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(self.0 ^ rhs.0)
             }
+        }
 
-            // std::hash::Hash implementation
+        impl<T: std::ops::BitXor<Output = T>> std::ops::BitXor<T> for $newtype<T> {
+            type Output = $newtype<T>;
 
-            impl<T: std::hash::Hash> std::hash::Hash for $newtype<T> {
-                fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-                    self.0.hash(state);
-                }
+            fn bitxor(self, rhs: T) -> Self::Output {
+                $newtype(self.0 ^ rhs)
             }
+        }
 
-            // std::ops implementations
+        impl<'a, 'b, T> std::ops::BitXor<&'b $newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitXor<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitxor(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(&self.0 ^ &rhs.0)
+            }
+        }
 
-            impl<T: std::ops::Add<Output = T>> std::ops::Add for $newtype<T> {
-                type Output = Self;
+        impl<'b, T> std::ops::BitXor<&'b $newtype<T>> for $newtype<T>
+        where
+            T: std::ops::BitXor<&'b T, Output = T>,
+        {
+            type Output = $newtype<T>;
 
-                fn add(self, other: Self) -> Self {
-                    Self(self.0 + other.0)
-                }
+            fn bitxor(self, rhs: &'b $newtype<T>) -> Self::Output {
+                $newtype(self.0 ^ &rhs.0)
             }
+        }
 
-            impl<T: std::ops::AddAssign> std::ops::AddAssign for $newtype<T> {
-                fn add_assign(&mut self, other: Self) {
-                    self.0 += other.0;
-                }
+        impl<'a, T> std::ops::BitXor<$newtype<T>> for &'a $newtype<T>
+        where
+            &'a T: std::ops::BitXor<T, Output = T>,
+        {
+            type Output = $newtype<T>;
+
+            fn bitxor(self, rhs: $newtype<T>) -> Self::Output {
+                $newtype(&self.0 ^ rhs.0)
             }
+        }
 
-            impl<T: std::ops::BitAnd<Output = T>> std::ops::BitAnd for $newtype<T> {
-                type Output = Self;
-                fn bitand(self, rhs: Self) -> Self::Output {
-                    Self(self.0 & rhs.0)
-                }
+        impl<T: std::ops::BitXorAssign> std::ops::BitXorAssign for $newtype<T> {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.0 ^= rhs.0;
             }
+        }
 
-            impl<T: std::ops::BitAndAssign + std::ops::BitAnd<Output = T> > std::ops::BitAndAssign for $newtype<T> {
-                fn bitand_assign(&mut self, rhs: Self) {
-                    self.0  &= rhs.0
-                }
+        impl<T: std::ops::BitXorAssign> std::ops::BitXorAssign<T> for $newtype<T> {
+            fn bitxor_assign(&mut self, rhs: T) {
+                self.0 ^= rhs;
             }
+        }
+    };
+
+    (@group Shl $newtype:ident) => {
+        impl<T, R> std::ops::Shl<R> for $newtype<T>
+        where
+            T: std::ops::Shl<R>,
+        {
+            type Output = $newtype<<T as std::ops::Shl<R>>::Output>;
 
-            impl<T: std::ops::BitOr<Output = T>> std::ops::BitOr for $newtype<T> {
-                type Output = Self;
+            fn shl(self, rhs: R) -> Self::Output {
+                $newtype(self.0 << rhs)
+            }
+        }
 
-                fn bitor(self, rhs: Self) -> Self {
-                    Self(self.0 | rhs.0)
-                }
+        impl<T: std::ops::ShlAssign> std::ops::ShlAssign for $newtype<T> {
+            fn shl_assign(&mut self, rhs: Self) {
+                self.0 <<= rhs.0;
             }
+        }
 
-            impl<T: std::ops::BitOrAssign> std::ops::BitOrAssign for $newtype<T> {
-                fn bitor_assign(&mut self, rhs: Self) {
-                    self.0 |= rhs.0
-                }
+        impl<T: std::ops::ShlAssign> std::ops::ShlAssign<T> for $newtype<T> {
+            fn shl_assign(&mut self, rhs: T) {
+                self.0 <<= rhs;
             }
+        }
+    };
 
-            impl<T: std::ops::BitXor<Output = T>> std::ops::BitXor for $newtype<T> {
-                type Output = Self;
+    (@group Shr $newtype:ident) => {
+        impl<T, R> std::ops::Shr<R> for $newtype<T>
+        where
+            T: std::ops::Shr<R>,
+        {
+            type Output = $newtype<<T as std::ops::Shr<R>>::Output>;
 
-                fn bitxor(self, rhs: Self) -> Self::Output {
-                    Self(self.0 ^ rhs.0)
-                }
+            fn shr(self, rhs: R) -> Self::Output {
+                $newtype(self.0 >> rhs)
             }
+        }
 
-            impl<T: std::ops::BitXorAssign> std::ops::BitXorAssign for $newtype<T> {
-                fn bitxor_assign(&mut self, rhs: Self) {
-                    self.0 ^= rhs.0
-                }
+        impl<T: std::ops::ShrAssign> std::ops::ShrAssign for $newtype<T> {
+            fn shr_assign(&mut self, rhs: Self) {
+                self.0 >>= rhs.0;
             }
+        }
 
-            impl<T: std::ops::Div<Output = T>> std::ops::Div for $newtype<T> {
-                type Output = Self;
+        impl<T: std::ops::ShrAssign> std::ops::ShrAssign<T> for $newtype<T> {
+            fn shr_assign(&mut self, rhs: T) {
+                self.0 >>= rhs;
+            }
+        }
+    };
 
-                fn div(self, rhs: Self) -> Self::Output {
-                    Self(self.0 / rhs.0)
-                }
+    (@group Neg $newtype:ident) => {
+        impl<T: std::ops::Neg<Output = T>> std::ops::Neg for $newtype<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
             }
+        }
+    };
+
+    (@group Not $newtype:ident) => {
+        impl<T: std::ops::Not<Output = T>> std::ops::Not for $newtype<T> {
+            type Output = Self;
 
-            impl<T: std::ops::DivAssign> std::ops::DivAssign for $newtype<T> {
-                fn div_assign(&mut self, rhs: Self) {
-                    self.0 /= rhs.0
-                }
+            fn not(self) -> Self::Output {
+                Self(!self.0)
             }
+        }
+    };
 
-            impl<T: std::ops::Mul<Output = T>> std::ops::Mul for $newtype<T> {
-                type Output = Self;
+    (@group Display $newtype:ident) => {
+        impl<T: std::fmt::Display> std::fmt::Display for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
 
-                fn mul(self, rhs: Self) -> Self {
-                    Self(self.0 * rhs.0)
-                }
+    (@group Binary $newtype:ident) => {
+        impl<T: std::fmt::Binary> std::fmt::Binary for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
             }
+        }
+    };
 
-            impl<T: std::ops::MulAssign> std::ops::MulAssign for $newtype<T> {
-                fn mul_assign(&mut self, rhs: Self) {
-                    self.0 *= rhs.0
-                }
+    (@group Octal $newtype:ident) => {
+        impl<T: std::fmt::Octal> std::fmt::Octal for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
             }
+        }
+    };
 
-            impl<T: std::ops::Not<Output = T>> std::ops::Not for $newtype<T> {
-                type Output = Self;
+    (@group LowerHex $newtype:ident) => {
+        impl<T: std::fmt::LowerHex> std::fmt::LowerHex for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
 
-                fn not(self) -> Self::Output {
-                    Self(!self.0)
-                }
+    (@group UpperHex $newtype:ident) => {
+        impl<T: std::fmt::UpperHex> std::fmt::UpperHex for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
             }
+        }
+    };
 
-            impl<T: std::ops::Rem<Output = T>> std::ops::Rem for $newtype<T> {
-                type Output = Self;
+    (@group LowerExp $newtype:ident) => {
+        impl<T: std::fmt::LowerExp> std::fmt::LowerExp for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
 
-                fn rem(self, modulus: Self) -> Self::Output {
-                    Self(self.0 % modulus.0)
-                }
+    (@group UpperExp $newtype:ident) => {
+        impl<T: std::fmt::UpperExp> std::fmt::UpperExp for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
             }
+        }
+    };
 
-            impl<T: std::ops::RemAssign> std::ops::RemAssign for $newtype<T> {
-                fn rem_assign(&mut self, modulus: Self) {
-                    self.0 %= modulus.0;
-                }
+    (@group Pointer $newtype:ident) => {
+        impl<T: std::fmt::Pointer> std::fmt::Pointer for $newtype<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
             }
+        }
+    };
 
-            impl<T: std::ops::Sub<Output = T>> std::ops::Sub for $newtype<T> {
-                type Output = Self;
+    (@group FromStr $newtype:ident) => {
+        impl<T: std::str::FromStr> std::str::FromStr for $newtype<T> {
+            type Err = <T as std::str::FromStr>::Err;
 
-                fn sub(self, other: Self) -> Self {
-                    Self(self.0 - other.0)
-                }
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(s.parse()?))
             }
+        }
+    };
 
-            impl<T: std::ops::SubAssign> std::ops::SubAssign for $newtype<T> {
-                fn sub_assign(&mut self, other: Self) {
-                    self.0 -= other.0
-                }
+    (@group IntoIterator $newtype:ident) => {
+        impl<T: std::iter::IntoIterator> std::iter::IntoIterator for $newtype<T> {
+            type Item = <T as std::iter::IntoIterator>::Item;
+            type IntoIter = <T as std::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
             }
+        }
 
-            impl<T: std::ops::Neg<Output = T>> std::ops::Neg for $newtype<T> {
-                type Output = Self;
+        impl<'a, T> std::iter::IntoIterator for &'a $newtype<T>
+        where
+            &'a T: std::iter::IntoIterator,
+        {
+            type Item = <&'a T as std::iter::IntoIterator>::Item;
+            type IntoIter = <&'a T as std::iter::IntoIterator>::IntoIter;
 
-                fn neg(self) -> Self::Output {
-                    Self(-self.0)
-                }
+            fn into_iter(self) -> Self::IntoIter {
+                (&self.0).into_iter()
             }
+        }
 
-            impl<T: std::ops::Shl<Output = T>> std::ops::Shl for $newtype<T> {
-                type Output = Self;
+        impl<'a, T> std::iter::IntoIterator for &'a mut $newtype<T>
+        where
+            &'a mut T: std::iter::IntoIterator,
+        {
+            type Item = <&'a mut T as std::iter::IntoIterator>::Item;
+            type IntoIter = <&'a mut T as std::iter::IntoIterator>::IntoIter;
 
-                fn shl(self, rhs: Self) -> Self {
-                    Self(self.0 << rhs.0)
-                }
+            fn into_iter(self) -> Self::IntoIter {
+                (&mut self.0).into_iter()
             }
+        }
+    };
 
-            impl<T: std::ops::ShlAssign> std::ops::ShlAssign for $newtype<T> {
-                fn shl_assign(&mut self, rhs: Self) {
-                    self.0 <<= rhs.0;
-                }
+    (@group Extend $newtype:ident) => {
+        impl<U, T: std::iter::Extend<U>> std::iter::Extend<U> for $newtype<T> {
+            fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+                self.0.extend(iter);
             }
+        }
+    };
 
-            impl<T: std::ops::Shr<Output = T>> std::ops::Shr for $newtype<T> {
-                type Output = Self;
+    (@group Iterator $newtype:ident) => {
+        impl<T: std::iter::Iterator> std::iter::Iterator for $newtype<T> {
+            type Item = <T as std::iter::Iterator>::Item;
 
-                fn shr(self, rhs: Self) -> Self {
-                    Self(self.0 >> rhs.0)
-                }
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
             }
+        }
+    };
+
+    (@group Sum $newtype:ident) => {
+        impl<T: std::iter::Sum> std::iter::Sum<$newtype<T>> for $newtype<T> {
+            fn sum<I: std::iter::Iterator<Item = $newtype<T>>>(iter: I) -> Self {
+                Self(iter.map(|value| value.0).sum())
+            }
+        }
+    };
 
-            impl<T: std::ops::ShrAssign> std::ops::ShrAssign for $newtype<T> {
-                fn shr_assign(&mut self, rhs: Self) {
-                    self.0 >>= rhs.0;
-                }
+    (@group Product $newtype:ident) => {
+        impl<T: std::iter::Product> std::iter::Product<$newtype<T>> for $newtype<T> {
+            fn product<I: std::iter::Iterator<Item = $newtype<T>>>(iter: I) -> Self {
+                Self(iter.map(|value| value.0).product())
             }
-        )*
+        }
     };
+
 }
 
 #[cfg(test)]
@@ -383,6 +1070,9 @@ mod tests {
     use std::collections::HashSet;
 
     #[test]
+    // `id.clone()` below deliberately exercises the generated `Clone` impl even
+    // though the inner type is also `Copy`.
+    #[allow(clippy::clone_on_copy)]
     fn it_works() {
         newtype!(Id, Nested);
 
@@ -451,18 +1141,85 @@ mod tests {
         assert_eq!(id_1, Id(-1));
         // Neg
         assert_eq!(-Id(1), Id(-1));
-        // Shl
-        assert_eq!(Id(1) << Id(1), Id(2));
+        // Shl (shift by a raw integer count)
+        assert_eq!(Id(1) << 1, Id(2));
         // ShlAssign
         id_1 <<= Id(1);
         assert_eq!(id_1, Id(-2));
-        // Shr
-        assert_eq!(Id(1) >> Id(1), Id(0));
+        // Shr (shift by a raw integer count)
+        assert_eq!(Id(1) >> 1, Id(0));
         // ShrAssign
         id_1 >>= Id(1);
         assert_eq!(id_1, Id(-1));
     }
 
+    #[test]
+    // The point of the borrowed-operand forms is that they exist; on a `Copy`
+    // inner type clippy would rather we pass by value.
+    #[allow(clippy::op_ref)]
+    fn raw_and_reference_operands() {
+        newtype!(Meters: f32);
+
+        // Arithmetic directly against the bare wrapped value.
+        assert_eq!(Meters(10.0f32) + 5.0f32, Meters(15.0f32));
+        assert_eq!(Meters(10.0f32) - 4.0f32, Meters(6.0f32));
+        assert_eq!(Meters(10.0f32) * 2.0f32, Meters(20.0f32));
+        assert_eq!(Meters(10.0f32) / 2.0f32, Meters(5.0f32));
+
+        // Borrowed operands on the homogeneous newtype case.
+        let a = Meters(3.0f32);
+        let b = Meters(4.0f32);
+        assert_eq!(&a + &b, Meters(7.0f32));
+        assert_eq!(a + &b, Meters(7.0f32));
+        assert_eq!(&a + b, Meters(7.0f32));
+
+        // Raw-value compound assignment.
+        let mut m = Meters(1.0f32);
+        m += 2.0f32;
+        assert_eq!(m, Meters(3.0f32));
+    }
+
+    #[test]
+    fn heterogeneous_output() {
+        // An inner type whose `Mul` yields a *different* type: the newtype
+        // forwards that associated `Output` instead of being pinned to `Self`.
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        struct Ft(i32);
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        struct Sqft(i32);
+        impl std::ops::Mul for Ft {
+            type Output = Sqft;
+            fn mul(self, rhs: Ft) -> Sqft {
+                Sqft(self.0 * rhs.0)
+            }
+        }
+
+        newtype!(Area);
+        let area: Area<Sqft> = Area(Ft(2)) * Area(Ft(3));
+        assert_eq!(area, Area(Sqft(6)));
+
+        // Shifting is by a raw integer count, as the inner type expects.
+        newtype!(Bits);
+        assert_eq!(Bits(1u32) << 3usize, Bits(8u32));
+        assert_eq!(Bits(8u32) >> 2usize, Bits(2u32));
+    }
+
+    #[test]
+    fn formatting_and_parsing() {
+        newtype!(Token);
+
+        let t = Token(255u32);
+        // `Display` and the width / `#` / precision flags pass straight through.
+        assert_eq!(format!("{}", t), "255");
+        assert_eq!(format!("{:#x}", t), "0xff");
+        assert_eq!(format!("{:08b}", Token(5u8)), "00000101");
+
+        // `FromStr` delegates to the inner type, surfacing its `Err`.
+        let parsed: Token<i32> = "42".parse().unwrap();
+        assert_eq!(parsed, Token(42));
+        assert!("nope".parse::<Token<i32>>().is_err());
+    }
+
     #[test]
     fn nested() {
         newtype!(A, B);
@@ -490,4 +1247,38 @@ mod tests {
 
         assert_eq!(a.len(), 2)
     }
+
+    #[test]
+    fn iteration() {
+        // `Sum` and `Product` over a stream of newtypes.
+        newtype!(Count);
+        let total: Count<i32> = [Count(1), Count(2), Count(3)].into_iter().sum();
+        assert_eq!(total, Count(6));
+        let product: Count<i32> = [Count(2), Count(3)].into_iter().product();
+        assert_eq!(product, Count(6));
+
+        // `Extend` by value and `IntoIterator` back out of a collection newtype.
+        newtype!(Bag);
+        let mut bag = Bag(vec![1, 2]);
+        bag.extend([3, 4]);
+        let collected: Vec<i32> = bag.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3, 4]);
+
+        // Borrowing iterates without consuming.
+        let bag = Bag(vec![10, 20]);
+        let sum: i32 = (&bag).into_iter().sum();
+        assert_eq!(sum, 30);
+    }
+
+    #[test]
+    fn iterator_opt_in() {
+        // `Iterator` clashes with the default `IntoIterator` forwarding, so it
+        // is only available when explicitly requested.
+        newtype!(Counter; only: Iterator);
+        let mut counter = Counter(0..3);
+        assert_eq!(counter.next(), Some(0));
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), None);
+    }
 }